@@ -1,12 +1,19 @@
 use std::path::PathBuf;
+use std::time::Instant;
 
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use chrono::{NaiveDate, TimeZone, Utc};
 use polars::lazy::dsl::{col, lit};
 use polars::prelude::*;
 
-use crate::models::{Candle, IndicatorPoint, IndicatorSeries};
+use crate::filter;
+use crate::indicators;
+use crate::metrics;
+use crate::models::{ApiError, Candle, ContractInfo, IndicatorPoint, IndicatorSeries};
 
 const DEFAULT_PARQUET_PATH: &str = "../sample.parquet";
+const ALLOWED_INTERVALS: [&str; 5] = ["1m", "5m", "15m", "1h", "1d"];
+const OHLC_COLUMNS: [&str; 4] = ["open", "high", "low", "close"];
 const INDICATOR_COLUMNS: [&str; 9] = [
     "vwap",
     "vwapn",
@@ -19,6 +26,11 @@ const INDICATOR_COLUMNS: [&str; 9] = [
     "atr_14",
 ];
 
+/// Returns the full set of columns a `filter` expression is allowed to reference.
+fn filterable_columns() -> Vec<&'static str> {
+    OHLC_COLUMNS.iter().chain(INDICATOR_COLUMNS.iter()).copied().collect()
+}
+
 /// Reads market bars and indicator values from a configured Parquet source.
 #[derive(Clone)]
 pub struct BarsRepository {
@@ -35,17 +47,54 @@ impl BarsRepository {
         Self { parquet_path }
     }
 
-    /// Returns candles for an optional contract and optional inclusive date window.
+    /// Returns a page of candles for an optional contract, optional inclusive date window, and
+    /// optional resampling interval. When `interval` is set, bars are aggregated on the fly with
+    /// `open`/`high`/`low`/`close` reduced to first/max/min/last within each bucket. When `limit`
+    /// is set, at most `limit` candles are returned along with an opaque `next_cursor` that can
+    /// be replayed as `cursor` to fetch the following page; omitting `limit` preserves the
+    /// original unpaginated behavior.
     pub fn load_bars(
         &self,
         contract: Option<&str>,
         start: Option<&str>,
         end: Option<&str>,
-    ) -> Result<Vec<Candle>, String> {
-        let start_time = parse_start_date(start)?;
-        let end_time = parse_end_date(end)?;
+        interval: Option<&str>,
+        limit: Option<usize>,
+        cursor: Option<&str>,
+        filter_expr: Option<&str>,
+    ) -> Result<(Vec<Candle>, Option<String>), Vec<ApiError>> {
+        let mut errors = Vec::new();
+
+        let start_time = parse_start_date(start).unwrap_or_else(|error| {
+            errors.push(error);
+            None
+        });
+        let end_time = parse_end_date(end).unwrap_or_else(|error| {
+            errors.push(error);
+            None
+        });
+        let interval = parse_interval(interval).unwrap_or_else(|error| {
+            errors.push(error);
+            None
+        });
+        let cursor_time = decode_cursor(cursor).unwrap_or_else(|error| {
+            errors.push(error);
+            None
+        });
+        let filter = parse_filter_expr(filter_expr).unwrap_or_else(|error| {
+            errors.push(error);
+            None
+        });
 
-        let mut query = self.base_query()?;
+        if let Err(error) = self.validate_contract(contract) {
+            errors.push(error);
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let mut query = self.base_query(filter.as_ref()).map_err(|error| vec![error])?;
 
         if let Some(contract) = contract {
             query = query.filter(col("contract").eq(lit(contract)));
@@ -67,32 +116,134 @@ impl BarsRepository {
             );
         }
 
-        let df = query
-            .select([
-                col("timestamp"),
-                col("open"),
-                col("high"),
-                col("low"),
-                col("close"),
-            ])
-            .sort(["timestamp"], Default::default())
-            .collect()
-            .map_err(|error| format!("Failed to load bars from parquet: {error}"))?;
+        // The cursor encodes the timestamp of the last row/bucket already returned. When
+        // resampling, that timestamp is a bucket start, not a raw row timestamp, so filtering the
+        // raw rows here would only drop the bucket's first row and let the rest of that same
+        // bucket back in, re-emitting a partially-built candle. Apply it to raw rows only in the
+        // unresampled case; the resampled case filters after aggregation instead.
+        if interval.is_none() {
+            if let Some(cursor_time) = cursor_time {
+                query = query.filter(
+                    col("timestamp")
+                        .cast(DataType::Int64)
+                        .gt(lit(cursor_time * 1000)),
+                );
+            }
+        }
+
+        query = query.select([
+            col("timestamp"),
+            col("contract"),
+            col("open"),
+            col("high"),
+            col("low"),
+            col("close"),
+        ]);
+
+        let mut query = match interval {
+            Some(every) => {
+                let mut aggregated = query
+                    .sort(["timestamp"], Default::default())
+                    .group_by_dynamic(
+                        col("timestamp"),
+                        [col("contract")],
+                        DynamicGroupOptions {
+                            every,
+                            period: every,
+                            offset: Duration::parse("0s"),
+                            label: Label::Left,
+                            include_boundaries: false,
+                            closed_window: ClosedWindow::Left,
+                            start_by: StartBy::WindowBound,
+                            ..Default::default()
+                        },
+                    )
+                    .agg([
+                        col("open").first().alias("open"),
+                        col("high").max().alias("high"),
+                        col("low").min().alias("low"),
+                        col("close").last().alias("close"),
+                    ])
+                    .sort(["timestamp"], Default::default());
+
+                if let Some(cursor_time) = cursor_time {
+                    aggregated = aggregated.filter(
+                        col("timestamp")
+                            .cast(DataType::Int64)
+                            .gt(lit(cursor_time * 1000)),
+                    );
+                }
+
+                aggregated
+            }
+            None => query.sort(["timestamp"], Default::default()),
+        };
+
+        if let Some(limit) = limit {
+            query = query.limit(limit as IdxSize + 1);
+        }
 
-        map_dataframe_to_candles(df)
+        let collect_started = Instant::now();
+        let df = query.collect().map_err(|error| {
+            vec![ApiError::new(
+                "query_failed",
+                format!("Failed to load bars from parquet: {error}"),
+            )]
+        })?;
+        metrics::observe_collect_duration("bars", collect_started.elapsed().as_secs_f64());
+
+        let (df, next_cursor) = paginate_dataframe(df, limit).map_err(|error| vec![error])?;
+        let candles = map_dataframe_to_candles(df).map_err(|error| vec![error])?;
+
+        Ok((candles, next_cursor))
     }
 
-    /// Returns chart-ready indicator series for available indicator columns.
+    /// Returns a page of chart-ready indicator series for the requested indicator ids, or the
+    /// historical precomputed-column set when `indicators` is omitted. An id already present as
+    /// a Parquet column is read directly; anything missing is derived on the fly from OHLC(V) via
+    /// the `indicators` module, and an id that is neither a column nor computable there is left
+    /// out of the response. When `limit` is set, at most `limit` rows worth of points are
+    /// returned per series along with an opaque `next_cursor` that can be replayed as `cursor` to
+    /// fetch the following page; omitting `limit` preserves the original unpaginated behavior.
     pub fn load_series(
         &self,
         contract: Option<&str>,
         start: Option<&str>,
         end: Option<&str>,
-    ) -> Result<Vec<IndicatorSeries>, String> {
-        let start_time = parse_start_date(start)?;
-        let end_time = parse_end_date(end)?;
+        limit: Option<usize>,
+        cursor: Option<&str>,
+        filter_expr: Option<&str>,
+        indicators: Option<&str>,
+    ) -> Result<(Vec<IndicatorSeries>, Option<String>), Vec<ApiError>> {
+        let mut errors = Vec::new();
+
+        let start_time = parse_start_date(start).unwrap_or_else(|error| {
+            errors.push(error);
+            None
+        });
+        let end_time = parse_end_date(end).unwrap_or_else(|error| {
+            errors.push(error);
+            None
+        });
+        let cursor_time = decode_cursor(cursor).unwrap_or_else(|error| {
+            errors.push(error);
+            None
+        });
+        let filter = parse_filter_expr(filter_expr).unwrap_or_else(|error| {
+            errors.push(error);
+            None
+        });
+        let requested = parse_requested_indicators(indicators);
 
-        let mut query = self.base_query()?;
+        if let Err(error) = self.validate_contract(contract) {
+            errors.push(error);
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let mut query = self.base_query(filter.as_ref()).map_err(|error| vec![error])?;
 
         if let Some(contract) = contract {
             query = query.filter(col("contract").eq(lit(contract)));
@@ -114,67 +265,269 @@ impl BarsRepository {
             );
         }
 
-        let select_exprs = std::iter::once(col("timestamp"))
-            .chain(INDICATOR_COLUMNS.into_iter().map(col))
-            .collect::<Vec<_>>();
+        if let Some(cursor_time) = cursor_time {
+            query = query.filter(
+                col("timestamp")
+                    .cast(DataType::Int64)
+                    .gt(lit(cursor_time * 1000)),
+            );
+        }
 
-        let df = query
+        let schema = query.clone().schema().map_err(|error| {
+            vec![ApiError::new(
+                "query_failed",
+                format!("Failed to read parquet schema: {error}"),
+            )]
+        })?;
+        let has_column = |name: &str| schema.get(name).is_some();
+        let has_volume = has_column("volume");
+
+        let mut select_exprs = vec![col("timestamp"), col("open"), col("high"), col("low"), col("close")];
+        if has_volume {
+            select_exprs.push(col("volume"));
+        }
+        for id in &requested {
+            if has_column(id) {
+                select_exprs.push(col(id.as_str()));
+            }
+        }
+
+        let mut query = query
             .select(select_exprs)
-            .sort(["timestamp"], Default::default())
-            .collect()
-            .map_err(|error| format!("Failed to load series from parquet: {error}"))?;
+            .sort(["timestamp"], Default::default());
+
+        if let Some(limit) = limit {
+            query = query.limit(limit as IdxSize + 1);
+        }
+
+        let collect_started = Instant::now();
+        let df = query.collect().map_err(|error| {
+            vec![ApiError::new(
+                "query_failed",
+                format!("Failed to load series from parquet: {error}"),
+            )]
+        })?;
+        metrics::observe_collect_duration("series", collect_started.elapsed().as_secs_f64());
 
-        map_dataframe_to_series(df)
+        let (df, next_cursor) = paginate_dataframe(df, limit).map_err(|error| vec![error])?;
+        let series = map_dataframe_to_series(&df, &requested, has_volume).map_err(|error| vec![error])?;
+
+        Ok((series, next_cursor))
     }
 
-    fn base_query(&self) -> Result<LazyFrame, String> {
+    /// Returns, for every distinct contract in the configured Parquet source, the min/max bar
+    /// timestamp (as epoch seconds) and the row count, sorted by contract identifier.
+    pub fn load_contracts(&self) -> Result<Vec<ContractInfo>, ApiError> {
+        let query = self.base_query(None)?;
+
+        let df = query
+            .group_by([col("contract")])
+            .agg([
+                col("timestamp").min().alias("start_ms"),
+                col("timestamp").max().alias("end_ms"),
+                col("timestamp").count().alias("row_count"),
+            ])
+            .sort(["contract"], Default::default());
+
+        let collect_started = Instant::now();
+        let df = df.collect().map_err(|error| {
+            ApiError::new("query_failed", format!("Failed to load contracts from parquet: {error}"))
+        })?;
+        metrics::observe_collect_duration("contracts", collect_started.elapsed().as_secs_f64());
+
+        map_dataframe_to_contracts(df)
+    }
+
+    /// Opens the configured Parquet source as a lazy frame, without recording a load metric.
+    /// Shared by `base_query`, which records the outcome of the "real" per-request load, and
+    /// `validate_contract`, which performs its own ad hoc lookup and would otherwise double-count
+    /// every successful load that also happens to filter by contract.
+    fn open_scan(&self) -> Result<LazyFrame, ApiError> {
         if !self.parquet_path.exists() {
-            return Err(format!(
-                "Parquet file not found at '{}'. Set BARS_PARQUET_PATH to override.",
-                self.parquet_path.display()
+            return Err(ApiError::new(
+                "data_source_missing",
+                format!(
+                    "Parquet file not found at '{}'. Set BARS_PARQUET_PATH to override.",
+                    self.parquet_path.display()
+                ),
             ));
         }
 
-        LazyFrame::scan_parquet(self.parquet_path.clone(), ScanArgsParquet::default()).map_err(
-            |error| {
+        LazyFrame::scan_parquet(self.parquet_path.clone(), ScanArgsParquet::default()).map_err(|error| {
+            ApiError::new(
+                "data_source_missing",
                 format!(
                     "Unable to open parquet file '{}': {error}",
                     self.parquet_path.display()
-                )
-            },
-        )
+                ),
+            )
+        })
+    }
+
+    /// Opens the configured Parquet source as a lazy frame and applies an already-parsed `filter`
+    /// DSL expression, if one was supplied, before any caller-specific filtering runs. Parsing
+    /// happens in `parse_filter_expr` so callers can accumulate that error alongside every other
+    /// validation failure instead of only discovering it here.
+    fn base_query(&self, filter_expr: Option<&filter::Expr>) -> Result<LazyFrame, ApiError> {
+        let scan = self.open_scan();
+        metrics::record_parquet_load(scan.is_ok());
+        let mut query = scan?;
+
+        if let Some(filter_expr) = filter_expr {
+            // `filterable_columns` allows every known indicator id, but an id only becomes a real
+            // column when the parquet has it precomputed; anything else is instead derived in
+            // Rust after `collect()`, too late for a predicate. Check the schema so a filter on a
+            // not-yet-computed indicator is reported as a client error rather than surfacing as a
+            // generic `collect()` failure.
+            let schema = query.clone().schema().map_err(|error| {
+                ApiError::new("query_failed", format!("Failed to read parquet schema: {error}"))
+            })?;
+
+            for column in filter_expr.referenced_columns() {
+                if schema.get(column).is_none() {
+                    return Err(ApiError::new(
+                        "filter_column_unavailable",
+                        format!(
+                            "Filter column '{column}' is not available in the configured Parquet source"
+                        ),
+                    ));
+                }
+            }
+
+            query = query.filter(filter_expr.to_predicate());
+        }
+
+        Ok(query)
+    }
+
+    /// Checks that `contract`, if given, actually appears in the configured Parquet source. A
+    /// failure to even open the source is left to `base_query` to report, so this treats that
+    /// case as "can't tell" rather than "unknown contract".
+    fn validate_contract(&self, contract: Option<&str>) -> Result<(), ApiError> {
+        let Some(contract) = contract else {
+            return Ok(());
+        };
+
+        let exists = self
+            .open_scan()
+            .ok()
+            .and_then(|query| {
+                query
+                    .filter(col("contract").eq(lit(contract)))
+                    .select([col("contract")])
+                    .limit(1)
+                    .collect()
+                    .ok()
+            })
+            .map(|df| df.height() > 0)
+            .unwrap_or(true);
+
+        if exists {
+            Ok(())
+        } else {
+            Err(ApiError::new("unknown_contract", format!("Unknown contract '{contract}'")))
+        }
+    }
+}
+
+/// Parses the `filter` query parameter into a predicate expression, if one was supplied. Kept
+/// separate from `base_query` so callers can accumulate a parse failure alongside every other
+/// validation error instead of only surfacing it once the query actually runs.
+fn parse_filter_expr(filter_expr: Option<&str>) -> Result<Option<filter::Expr>, ApiError> {
+    let Some(filter_expr) = filter_expr else {
+        return Ok(None);
+    };
+
+    let allowed_columns = filterable_columns();
+    filter::parse(filter_expr, &allowed_columns)
+        .map(Some)
+        .map_err(|error| ApiError::new("invalid_filter", format!("Invalid filter expression: {error}")))
+}
+
+/// Decodes an opaque pagination cursor back into the epoch-second timestamp it encodes.
+fn decode_cursor(value: Option<&str>) -> Result<Option<i64>, ApiError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    let invalid = || ApiError::new("invalid_cursor", format!("Invalid cursor '{value}'."));
+
+    let decoded = STANDARD.decode(value).map_err(|_| invalid())?;
+    let text = String::from_utf8(decoded).map_err(|_| invalid())?;
+
+    text.parse::<i64>().map(Some).map_err(|_| invalid())
+}
+
+/// Encodes an epoch-second timestamp as an opaque pagination cursor.
+fn encode_cursor(timestamp: i64) -> String {
+    STANDARD.encode(timestamp.to_string())
+}
+
+/// Trims a collected dataframe back to the requested page size and derives the cursor for the
+/// next page, if one exists. The dataframe is expected to have been fetched with `limit + 1`
+/// rows so an extra trailing row signals more data is available.
+fn paginate_dataframe(df: DataFrame, limit: Option<usize>) -> Result<(DataFrame, Option<String>), ApiError> {
+    let Some(limit) = limit else {
+        return Ok((df, None));
+    };
+
+    if limit == 0 {
+        return Ok((df.head(Some(0)), None));
     }
+
+    if df.height() <= limit {
+        return Ok((df, None));
+    }
+
+    let timestamps = df
+        .column("timestamp")
+        .map_err(|error| ApiError::new("query_failed", format!("Missing timestamp column: {error}")))?
+        .cast(&DataType::Int64)
+        .map_err(|error| ApiError::new("query_failed", format!("Timestamp conversion failed: {error}")))?;
+    let timestamp_values = timestamps
+        .i64()
+        .map_err(|error| ApiError::new("query_failed", format!("Timestamp conversion failed: {error}")))?;
+
+    let last_kept_ms = timestamp_values.get(limit - 1).ok_or_else(|| {
+        ApiError::new("query_failed", "Failed to determine pagination cursor".to_string())
+    })?;
+
+    Ok((df.head(Some(limit)), Some(encode_cursor(last_kept_ms / 1000))))
 }
 
-fn map_dataframe_to_candles(df: DataFrame) -> Result<Vec<Candle>, String> {
+fn map_dataframe_to_candles(df: DataFrame) -> Result<Vec<Candle>, ApiError> {
+    let query_failed = |what: &str, error: PolarsError| {
+        ApiError::new("query_failed", format!("{what}: {error}"))
+    };
+
     let timestamps = df
         .column("timestamp")
-        .map_err(|error| format!("Missing timestamp column: {error}"))?
+        .map_err(|error| query_failed("Missing timestamp column", error))?
         .cast(&DataType::Int64)
-        .map_err(|error| format!("Timestamp conversion failed: {error}"))?;
+        .map_err(|error| query_failed("Timestamp conversion failed", error))?;
     let opens = df
         .column("open")
-        .map_err(|error| format!("Missing open column: {error}"))?
+        .map_err(|error| query_failed("Missing open column", error))?
         .f64()
-        .map_err(|error| format!("Open conversion failed: {error}"))?;
+        .map_err(|error| query_failed("Open conversion failed", error))?;
     let highs = df
         .column("high")
-        .map_err(|error| format!("Missing high column: {error}"))?
+        .map_err(|error| query_failed("Missing high column", error))?
         .f64()
-        .map_err(|error| format!("High conversion failed: {error}"))?;
+        .map_err(|error| query_failed("High conversion failed", error))?;
     let lows = df
         .column("low")
-        .map_err(|error| format!("Missing low column: {error}"))?
+        .map_err(|error| query_failed("Missing low column", error))?
         .f64()
-        .map_err(|error| format!("Low conversion failed: {error}"))?;
+        .map_err(|error| query_failed("Low conversion failed", error))?;
     let closes = df
         .column("close")
-        .map_err(|error| format!("Missing close column: {error}"))?
+        .map_err(|error| query_failed("Missing close column", error))?
         .f64()
-        .map_err(|error| format!("Close conversion failed: {error}"))?;
+        .map_err(|error| query_failed("Close conversion failed", error))?;
     let timestamp_values = timestamps
         .i64()
-        .map_err(|error| format!("Timestamp conversion failed: {error}"))?;
+        .map_err(|error| query_failed("Timestamp conversion failed", error))?;
 
     let mut candles = Vec::with_capacity(df.height());
 
@@ -204,35 +557,169 @@ fn map_dataframe_to_candles(df: DataFrame) -> Result<Vec<Candle>, String> {
     Ok(candles)
 }
 
-fn map_dataframe_to_series(df: DataFrame) -> Result<Vec<IndicatorSeries>, String> {
+fn map_dataframe_to_contracts(df: DataFrame) -> Result<Vec<ContractInfo>, ApiError> {
+    let query_failed = |what: &str, error: PolarsError| {
+        ApiError::new("query_failed", format!("{what}: {error}"))
+    };
+
+    let contracts = df
+        .column("contract")
+        .map_err(|error| query_failed("Missing contract column", error))?
+        .str()
+        .map_err(|error| query_failed("Contract conversion failed", error))?;
+    let starts = df
+        .column("start_ms")
+        .map_err(|error| query_failed("Missing start_ms column", error))?
+        .cast(&DataType::Int64)
+        .map_err(|error| query_failed("Start timestamp conversion failed", error))?;
+    let ends = df
+        .column("end_ms")
+        .map_err(|error| query_failed("Missing end_ms column", error))?
+        .cast(&DataType::Int64)
+        .map_err(|error| query_failed("End timestamp conversion failed", error))?;
+    let row_counts = df
+        .column("row_count")
+        .map_err(|error| query_failed("Missing row_count column", error))?
+        .cast(&DataType::Int64)
+        .map_err(|error| query_failed("Row count conversion failed", error))?;
+
+    let start_values = starts.i64().map_err(|error| query_failed("Start timestamp conversion failed", error))?;
+    let end_values = ends.i64().map_err(|error| query_failed("End timestamp conversion failed", error))?;
+    let row_count_values = row_counts
+        .i64()
+        .map_err(|error| query_failed("Row count conversion failed", error))?;
+
+    let mut contract_infos = Vec::with_capacity(df.height());
+
+    for idx in 0..df.height() {
+        let (Some(contract), Some(start_ms), Some(end_ms), Some(row_count)) = (
+            contracts.get(idx),
+            start_values.get(idx),
+            end_values.get(idx),
+            row_count_values.get(idx),
+        ) else {
+            continue;
+        };
+
+        contract_infos.push(ContractInfo {
+            contract: contract.to_string(),
+            start: start_ms / 1000,
+            end: end_ms / 1000,
+            row_count,
+        });
+    }
+
+    Ok(contract_infos)
+}
+
+/// Returns the default indicator ids read when the caller doesn't pass `indicators` explicitly,
+/// or the caller's comma-separated selection when it does.
+fn parse_requested_indicators(value: Option<&str>) -> Vec<String> {
+    match value {
+        Some(value) => value
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect(),
+        None => INDICATOR_COLUMNS.iter().map(|id| id.to_string()).collect(),
+    }
+}
+
+/// Pane assignment for indicator ids that don't parse into a computable `IndicatorSpec` (for
+/// example `vwapn`/`vwapd`, which only ever come from a precomputed column).
+fn legacy_pane(id: &str) -> &'static str {
+    if id.starts_with("rsi") {
+        "rsi"
+    } else if id.starts_with("atr") {
+        "atr"
+    } else {
+        "price"
+    }
+}
+
+fn column_as_f64(df: &DataFrame, name: &str) -> Result<Vec<f64>, ApiError> {
+    df.column(name)
+        .map_err(|error| ApiError::new("query_failed", format!("Missing {name} column: {error}")))?
+        .f64()
+        .map_err(|error| ApiError::new("query_failed", format!("{name} conversion failed: {error}")))?
+        .into_iter()
+        .map(|value| {
+            value.ok_or_else(|| ApiError::new("query_failed", format!("Null value found in {name} column")))
+        })
+        .collect()
+}
+
+/// Computes an indicator from raw OHLC(V) columns already present in `df`.
+fn compute_indicator(df: &DataFrame, spec: indicators::IndicatorSpec) -> Result<Vec<f64>, ApiError> {
+    let closes = column_as_f64(df, "close")?;
+
+    match spec {
+        indicators::IndicatorSpec::Ema { period } => Ok(indicators::ema(&closes, period)),
+        indicators::IndicatorSpec::RsiWilder { period } => Ok(indicators::rsi_wilder(&closes, period)),
+        indicators::IndicatorSpec::RsiEma { period } => Ok(indicators::rsi_ema(&closes, period)),
+        indicators::IndicatorSpec::AtrWilder { period } => {
+            let highs = column_as_f64(df, "high")?;
+            let lows = column_as_f64(df, "low")?;
+            Ok(indicators::atr_wilder(&highs, &lows, &closes, period))
+        }
+        indicators::IndicatorSpec::Vwap => {
+            let highs = column_as_f64(df, "high")?;
+            let lows = column_as_f64(df, "low")?;
+            let volumes = column_as_f64(df, "volume")?;
+            Ok(indicators::vwap(&highs, &lows, &closes, &volumes))
+        }
+    }
+}
+
+fn map_dataframe_to_series(
+    df: &DataFrame,
+    requested: &[String],
+    has_volume: bool,
+) -> Result<Vec<IndicatorSeries>, ApiError> {
     let timestamps = df
         .column("timestamp")
-        .map_err(|error| format!("Missing timestamp column: {error}"))?
+        .map_err(|error| ApiError::new("query_failed", format!("Missing timestamp column: {error}")))?
         .cast(&DataType::Int64)
-        .map_err(|error| format!("Timestamp conversion failed: {error}"))?;
+        .map_err(|error| ApiError::new("query_failed", format!("Timestamp conversion failed: {error}")))?;
     let timestamp_values = timestamps
         .i64()
-        .map_err(|error| format!("Timestamp conversion failed: {error}"))?;
+        .map_err(|error| ApiError::new("query_failed", format!("Timestamp conversion failed: {error}")))?;
 
-    let mut series = Vec::with_capacity(INDICATOR_COLUMNS.len());
+    let mut series = Vec::with_capacity(requested.len());
 
-    for indicator in INDICATOR_COLUMNS {
-        let values = match df.column(indicator) {
-            Ok(column) => column
+    for id in requested {
+        let spec = indicators::IndicatorSpec::parse(id).ok();
+
+        let values: Vec<f64> = if let Ok(column) = df.column(id) {
+            let column = column
                 .f64()
-                .map_err(|error| format!("{indicator} conversion failed: {error}"))?,
-            Err(_) => continue,
+                .map_err(|error| ApiError::new("query_failed", format!("{id} conversion failed: {error}")))?;
+            (0..df.height()).map(|idx| column.get(idx).unwrap_or(f64::NAN)).collect()
+        } else {
+            let Some(spec) = spec else {
+                continue;
+            };
+
+            if spec.requires_volume() && !has_volume {
+                return Err(ApiError::new(
+                    "missing_volume_column",
+                    format!("Cannot compute '{id}': source parquet has no 'volume' column"),
+                ));
+            }
+
+            compute_indicator(df, spec)?
         };
 
+        let pane = spec.map(indicators::IndicatorSpec::pane).unwrap_or_else(|| legacy_pane(id));
+
         let mut points = Vec::new();
 
         for idx in 0..df.height() {
             let Some(timestamp_ms) = timestamp_values.get(idx) else {
                 continue;
             };
-            let Some(value) = values.get(idx) else {
-                continue;
-            };
+            let value = values[idx];
 
             if value.is_nan() {
                 continue;
@@ -244,17 +731,9 @@ fn map_dataframe_to_series(df: DataFrame) -> Result<Vec<IndicatorSeries>, String
             });
         }
 
-        let pane = if indicator.starts_with("rsi") {
-            "rsi"
-        } else if indicator.starts_with("atr") {
-            "atr"
-        } else {
-            "price"
-        };
-
         series.push(IndicatorSeries {
-            id: indicator.to_string(),
-            name: indicator.replace('_', " ").to_uppercase(),
+            id: id.clone(),
+            name: id.replace('_', " ").to_uppercase(),
             kind: "line".to_string(),
             pane: pane.to_string(),
             data: points,
@@ -265,7 +744,7 @@ fn map_dataframe_to_series(df: DataFrame) -> Result<Vec<IndicatorSeries>, String
 }
 
 /// Converts a date string into the first UTC second included in the requested range.
-fn parse_start_date(value: Option<&str>) -> Result<Option<i64>, String> {
+fn parse_start_date(value: Option<&str>) -> Result<Option<i64>, ApiError> {
     let Some(value) = value else {
         return Ok(None);
     };
@@ -278,7 +757,7 @@ fn parse_start_date(value: Option<&str>) -> Result<Option<i64>, String> {
 }
 
 /// Converts a date string into the final UTC second included in the requested range.
-fn parse_end_date(value: Option<&str>) -> Result<Option<i64>, String> {
+fn parse_end_date(value: Option<&str>) -> Result<Option<i64>, ApiError> {
     let Some(value) = value else {
         return Ok(None);
     };
@@ -291,9 +770,28 @@ fn parse_end_date(value: Option<&str>) -> Result<Option<i64>, String> {
 }
 
 /// Parses a YYYY-MM-DD date string into a chrono date value.
-fn parse_yyyy_mm_dd(value: &str) -> Result<NaiveDate, String> {
+fn parse_yyyy_mm_dd(value: &str) -> Result<NaiveDate, ApiError> {
     NaiveDate::parse_from_str(value, "%Y-%m-%d")
-        .map_err(|_| format!("Invalid date '{value}'. Expected YYYY-MM-DD."))
+        .map_err(|_| ApiError::new("invalid_date", format!("Invalid date '{value}'. Expected YYYY-MM-DD.")))
+}
+
+/// Validates a requested resampling interval and lowers it into a Polars bucket duration.
+fn parse_interval(value: Option<&str>) -> Result<Option<Duration>, ApiError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    if !ALLOWED_INTERVALS.contains(&value) {
+        return Err(ApiError::new(
+            "invalid_interval",
+            format!(
+                "Invalid interval '{value}'. Expected one of {}.",
+                ALLOWED_INTERVALS.join(", ")
+            ),
+        ));
+    }
+
+    Ok(Some(Duration::parse(value)))
 }
 
 #[cfg(test)]
@@ -303,20 +801,142 @@ mod tests {
     #[test]
     fn reads_sample_parquet_bars() {
         let repository = BarsRepository::new();
-        let bars = repository
-            .load_bars(None, None, None)
+        let (bars, next_cursor) = repository
+            .load_bars(None, None, None, None, None, None, None)
             .expect("parquet should load");
 
         assert!(!bars.is_empty());
+        assert!(next_cursor.is_none());
     }
 
     #[test]
     fn rejects_bad_dates() {
         let repository = BarsRepository::new();
-        let err = repository
-            .load_bars(None, Some("10/24/2024"), None)
+        let errors = repository
+            .load_bars(None, Some("10/24/2024"), None, None, None, None, None)
             .expect_err("invalid date must fail");
 
-        assert!(err.contains("Expected YYYY-MM-DD"));
+        assert!(errors.iter().any(|error| error.code == "invalid_date"
+            && error.message.contains("Expected YYYY-MM-DD")));
+    }
+
+    #[test]
+    fn rejects_unknown_interval() {
+        let repository = BarsRepository::new();
+        let errors = repository
+            .load_bars(None, None, None, Some("3m"), None, None, None)
+            .expect_err("unknown interval must fail");
+
+        assert!(errors
+            .iter()
+            .any(|error| error.code == "invalid_interval" && error.message.contains("Invalid interval")));
+    }
+
+    #[test]
+    fn resamples_bars_to_requested_interval() {
+        let repository = BarsRepository::new();
+        let (bars, _) = repository
+            .load_bars(None, None, None, Some("1d"), None, None, None)
+            .expect("parquet should load and resample");
+
+        assert!(!bars.is_empty());
+    }
+
+    #[test]
+    fn paginates_bars_with_cursor() {
+        let repository = BarsRepository::new();
+        let (first_page, next_cursor) = repository
+            .load_bars(None, None, None, None, Some(1), None, None)
+            .expect("parquet should load");
+
+        assert_eq!(first_page.len(), 1);
+        let next_cursor = next_cursor.expect("more data should remain");
+
+        let (second_page, _) = repository
+            .load_bars(None, None, None, None, Some(1), Some(&next_cursor), None)
+            .expect("parquet should load next page");
+
+        assert_eq!(second_page.len(), 1);
+        assert!(second_page[0].time > first_page[0].time);
+    }
+
+    #[test]
+    fn accepts_zero_limit_without_panicking() {
+        let repository = BarsRepository::new();
+        let (bars, next_cursor) = repository
+            .load_bars(None, None, None, None, Some(0), None, None)
+            .expect("zero limit should be a valid, empty page");
+
+        assert!(bars.is_empty());
+        assert!(next_cursor.is_none());
+    }
+
+    #[test]
+    fn applies_filter_expression() {
+        let repository = BarsRepository::new();
+        let (all_bars, _) = repository
+            .load_bars(None, None, None, None, None, None, None)
+            .expect("parquet should load");
+        let (filtered, _) = repository
+            .load_bars(None, None, None, None, None, None, Some("close > 0"))
+            .expect("parquet should load with filter");
+
+        assert!(filtered.len() <= all_bars.len());
+    }
+
+    #[test]
+    fn rejects_filter_with_unknown_column() {
+        let repository = BarsRepository::new();
+        let errors = repository
+            .load_bars(None, None, None, None, None, None, Some("volume > 100"))
+            .expect_err("unknown column must fail");
+
+        assert!(errors
+            .iter()
+            .any(|error| error.code == "invalid_filter" && error.message.contains("Unknown column")));
+    }
+
+    #[test]
+    fn rejects_filter_on_indicator_not_backed_by_a_column() {
+        let repository = BarsRepository::new();
+        let errors = repository
+            .load_bars(None, None, None, None, None, None, Some("ema_9 > 10"))
+            .expect_err("filtering on an uncomputed indicator must fail");
+
+        assert!(errors.iter().any(|error| error.code == "filter_column_unavailable"));
+    }
+
+    #[test]
+    fn accumulates_date_and_filter_errors_together() {
+        let repository = BarsRepository::new();
+        let errors = repository
+            .load_bars(None, Some("10/24/2024"), None, None, None, None, Some("volume > 100"))
+            .expect_err("invalid date and invalid filter must both fail");
+
+        assert!(errors.iter().any(|error| error.code == "invalid_date"));
+        assert!(errors.iter().any(|error| error.code == "invalid_filter"));
+    }
+
+    #[test]
+    fn loads_contracts_with_date_coverage() {
+        let repository = BarsRepository::new();
+        let contracts = repository.load_contracts().expect("parquet should load");
+
+        assert!(!contracts.is_empty());
+        for contract in &contracts {
+            assert!(contract.start <= contract.end);
+            assert!(contract.row_count > 0);
+        }
+    }
+
+    #[test]
+    fn computes_indicators_absent_from_parquet() {
+        let repository = BarsRepository::new();
+        let (series, _) = repository
+            .load_series(None, None, None, None, None, None, Some("ema_9"))
+            .expect("parquet should load and compute ema_9");
+
+        let ema = series.iter().find(|series| series.id == "ema_9");
+        assert!(ema.is_some());
     }
 }
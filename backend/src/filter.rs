@@ -0,0 +1,470 @@
+//! Recursive-descent parser and lowering for the `filter` query parameter accepted by `/bars`
+//! and `/series`, e.g. `rsi_14_wilder < 30 AND close > 25.5`.
+
+use polars::lazy::dsl::{col, lit, Expr as PolarsExpr};
+
+/// A comparison operator accepted between a column and a numeric literal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComparisonOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+/// A boolean combinator joining two filter expressions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogicOp {
+    And,
+    Or,
+}
+
+/// A parsed filter expression, with `NOT` binding tighter than `AND`, which binds tighter than
+/// `OR`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Comparison {
+        column: String,
+        op: ComparisonOp,
+        value: f64,
+    },
+    Logic {
+        lhs: Box<Expr>,
+        op: LogicOp,
+        rhs: Box<Expr>,
+    },
+    Not(Box<Expr>),
+}
+
+impl Expr {
+    /// Lowers this filter AST into a Polars predicate. Comparisons exclude NaN indicator values
+    /// so warm-up rows never match rather than being treated as a spurious pass or failure.
+    pub fn to_predicate(&self) -> PolarsExpr {
+        match self {
+            Expr::Comparison { column, op, value } => {
+                let column_expr = col(column.as_str());
+                let comparison = match op {
+                    ComparisonOp::Lt => column_expr.clone().lt(lit(*value)),
+                    ComparisonOp::Le => column_expr.clone().lt_eq(lit(*value)),
+                    ComparisonOp::Gt => column_expr.clone().gt(lit(*value)),
+                    ComparisonOp::Ge => column_expr.clone().gt_eq(lit(*value)),
+                    ComparisonOp::Eq => column_expr.clone().eq(lit(*value)),
+                    ComparisonOp::Ne => column_expr.clone().neq(lit(*value)),
+                };
+                column_expr.is_not_nan().and(comparison)
+            }
+            Expr::Logic { lhs, op, rhs } => {
+                let lhs = lhs.to_predicate();
+                let rhs = rhs.to_predicate();
+                match op {
+                    LogicOp::And => lhs.and(rhs),
+                    LogicOp::Or => lhs.or(rhs),
+                }
+            }
+            // Negating the inner predicate would turn its NaN exclusion into a match, so NaN rows
+            // in any referenced column are excluded again outside the negation.
+            Expr::Not(inner) => inner
+                .referenced_columns()
+                .into_iter()
+                .fold(inner.to_predicate().not(), |predicate, column| {
+                    col(column).is_not_nan().and(predicate)
+                }),
+        }
+    }
+
+    /// Returns every column name referenced anywhere in this expression, so a caller can check
+    /// they're actually present in a particular schema before lowering and running the predicate.
+    pub fn referenced_columns(&self) -> Vec<&str> {
+        let mut columns = Vec::new();
+        self.collect_columns(&mut columns);
+        columns
+    }
+
+    fn collect_columns<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Expr::Comparison { column, .. } => out.push(column.as_str()),
+            Expr::Logic { lhs, rhs, .. } => {
+                lhs.collect_columns(out);
+                rhs.collect_columns(out);
+            }
+            Expr::Not(inner) => inner.collect_columns(out),
+        }
+    }
+}
+
+/// Describes why a filter expression failed to parse, with the character offset of the failure so the
+/// caller can point the user back at the source text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl std::fmt::Display for FilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (at position {})", self.message, self.position)
+    }
+}
+
+/// Parses a filter expression string into an AST, rejecting any column not present in
+/// `allowed_columns`.
+pub fn parse(input: &str, allowed_columns: &[&str]) -> Result<Expr, FilterError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        allowed_columns,
+    };
+
+    let expr = parser.parse_or()?;
+
+    if let Some(token) = parser.peek() {
+        return Err(FilterError {
+            message: "Unexpected trailing input".to_string(),
+            position: token.position,
+        });
+    }
+
+    Ok(expr)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PositionedToken {
+    token: Token,
+    position: usize,
+}
+
+fn tokenize(input: &str) -> Result<Vec<PositionedToken>, FilterError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let ch = chars[i];
+        let start = i;
+
+        if ch.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        match ch {
+            '(' => {
+                tokens.push(PositionedToken {
+                    token: Token::LParen,
+                    position: start,
+                });
+                i += 1;
+            }
+            ')' => {
+                tokens.push(PositionedToken {
+                    token: Token::RParen,
+                    position: start,
+                });
+                i += 1;
+            }
+            '<' => {
+                i += 1;
+                let token = if chars.get(i) == Some(&'=') {
+                    i += 1;
+                    Token::Le
+                } else {
+                    Token::Lt
+                };
+                tokens.push(PositionedToken {
+                    token,
+                    position: start,
+                });
+            }
+            '>' => {
+                i += 1;
+                let token = if chars.get(i) == Some(&'=') {
+                    i += 1;
+                    Token::Ge
+                } else {
+                    Token::Gt
+                };
+                tokens.push(PositionedToken {
+                    token,
+                    position: start,
+                });
+            }
+            '=' => {
+                i += 1;
+                tokens.push(PositionedToken {
+                    token: Token::Eq,
+                    position: start,
+                });
+            }
+            '!' => {
+                i += 1;
+                if chars.get(i) == Some(&'=') {
+                    i += 1;
+                    tokens.push(PositionedToken {
+                        token: Token::Ne,
+                        position: start,
+                    });
+                } else {
+                    return Err(FilterError {
+                        message: "Expected '!=' but found '!'".to_string(),
+                        position: start,
+                    });
+                }
+            }
+            _ if ch.is_ascii_digit() || (ch == '-' && chars.get(i + 1).is_some_and(char::is_ascii_digit)) => {
+                let mut end = i + 1;
+                while end < chars.len() && (chars[end].is_ascii_digit() || chars[end] == '.') {
+                    end += 1;
+                }
+                let text: String = chars[start..end].iter().collect();
+                let value = text.parse::<f64>().map_err(|_| FilterError {
+                    message: format!("Invalid numeric literal '{text}'"),
+                    position: start,
+                })?;
+                tokens.push(PositionedToken {
+                    token: Token::Number(value),
+                    position: start,
+                });
+                i = end;
+            }
+            _ if ch.is_alphabetic() || ch == '_' => {
+                let mut end = i + 1;
+                while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+                    end += 1;
+                }
+                let text: String = chars[start..end].iter().collect();
+                let token = match text.to_ascii_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Ident(text),
+                };
+                tokens.push(PositionedToken {
+                    token,
+                    position: start,
+                });
+                i = end;
+            }
+            other => {
+                return Err(FilterError {
+                    message: format!("Unexpected character '{other}'"),
+                    position: start,
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<PositionedToken>,
+    position: usize,
+    allowed_columns: &'a [&'a str],
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&PositionedToken> {
+        self.tokens.get(self.position)
+    }
+
+    fn advance(&mut self) -> Option<&PositionedToken> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    fn end_position(&self) -> usize {
+        self.tokens.last().map(|token| token.position + 1).unwrap_or(0)
+    }
+
+    /// `or_expr := and_expr (OR and_expr)*`
+    fn parse_or(&mut self) -> Result<Expr, FilterError> {
+        let mut lhs = self.parse_and()?;
+
+        while matches!(self.peek().map(|t| &t.token), Some(Token::Or)) {
+            self.advance();
+            let rhs = self.parse_and()?;
+            lhs = Expr::Logic {
+                lhs: Box::new(lhs),
+                op: LogicOp::Or,
+                rhs: Box::new(rhs),
+            };
+        }
+
+        Ok(lhs)
+    }
+
+    /// `and_expr := not_expr (AND not_expr)*`
+    fn parse_and(&mut self) -> Result<Expr, FilterError> {
+        let mut lhs = self.parse_not()?;
+
+        while matches!(self.peek().map(|t| &t.token), Some(Token::And)) {
+            self.advance();
+            let rhs = self.parse_not()?;
+            lhs = Expr::Logic {
+                lhs: Box::new(lhs),
+                op: LogicOp::And,
+                rhs: Box::new(rhs),
+            };
+        }
+
+        Ok(lhs)
+    }
+
+    /// `not_expr := NOT not_expr | comparison`
+    fn parse_not(&mut self) -> Result<Expr, FilterError> {
+        if matches!(self.peek().map(|t| &t.token), Some(Token::Not)) {
+            self.advance();
+            let inner = self.parse_not()?;
+            return Ok(Expr::Not(Box::new(inner)));
+        }
+
+        self.parse_comparison()
+    }
+
+    /// `comparison := '(' or_expr ')' | column op number`
+    fn parse_comparison(&mut self) -> Result<Expr, FilterError> {
+        if matches!(self.peek().map(|t| &t.token), Some(Token::LParen)) {
+            self.advance();
+            let inner = self.parse_or()?;
+            match self.advance() {
+                Some(token) if token.token == Token::RParen => return Ok(inner),
+                Some(token) => {
+                    return Err(FilterError {
+                        message: "Expected closing ')'".to_string(),
+                        position: token.position,
+                    });
+                }
+                None => {
+                    return Err(FilterError {
+                        message: "Expected closing ')'".to_string(),
+                        position: self.end_position(),
+                    });
+                }
+            }
+        }
+
+        let column_token = self.advance().ok_or_else(|| FilterError {
+            message: "Expected a column name".to_string(),
+            position: self.end_position(),
+        })?;
+
+        let (column, column_position) = match &column_token.token {
+            Token::Ident(name) => (name.clone(), column_token.position),
+            _ => {
+                return Err(FilterError {
+                    message: "Expected a column name".to_string(),
+                    position: column_token.position,
+                });
+            }
+        };
+
+        if !self.allowed_columns.contains(&column.as_str()) {
+            return Err(FilterError {
+                message: format!("Unknown column '{column}'"),
+                position: column_position,
+            });
+        }
+
+        let op_token = self.advance().ok_or_else(|| FilterError {
+            message: "Expected one of < <= > >= = !=".to_string(),
+            position: self.end_position(),
+        })?;
+
+        let op = match &op_token.token {
+            Token::Lt => ComparisonOp::Lt,
+            Token::Le => ComparisonOp::Le,
+            Token::Gt => ComparisonOp::Gt,
+            Token::Ge => ComparisonOp::Ge,
+            Token::Eq => ComparisonOp::Eq,
+            Token::Ne => ComparisonOp::Ne,
+            _ => {
+                return Err(FilterError {
+                    message: "Expected one of < <= > >= = !=".to_string(),
+                    position: op_token.position,
+                });
+            }
+        };
+
+        let value_token = self.advance().ok_or_else(|| FilterError {
+            message: "Expected a numeric literal".to_string(),
+            position: self.end_position(),
+        })?;
+
+        let value = match &value_token.token {
+            Token::Number(value) => *value,
+            _ => {
+                return Err(FilterError {
+                    message: "Expected a numeric literal".to_string(),
+                    position: value_token.position,
+                });
+            }
+        };
+
+        Ok(Expr::Comparison { column, op, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALLOWED: [&str; 2] = ["close", "rsi_14_wilder"];
+
+    #[test]
+    fn parses_simple_comparison() {
+        let expr = parse("close > 25.5", &ALLOWED).expect("should parse");
+        assert_eq!(
+            expr,
+            Expr::Comparison {
+                column: "close".to_string(),
+                op: ComparisonOp::Gt,
+                value: 25.5,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_and_with_correct_precedence() {
+        let expr = parse("rsi_14_wilder < 30 AND close > 1 OR close < 1", &ALLOWED)
+            .expect("should parse");
+
+        match expr {
+            Expr::Logic { op: LogicOp::Or, .. } => {}
+            other => panic!("expected OR at the top level, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_column() {
+        let error = parse("volume > 100", &ALLOWED).expect_err("should fail");
+        assert!(error.message.contains("Unknown column"));
+        assert_eq!(error.position, 0);
+    }
+
+    #[test]
+    fn rejects_trailing_garbage() {
+        let error = parse("close > 1 close", &ALLOWED).expect_err("should fail");
+        assert_eq!(error.position, "close > 1 ".len());
+    }
+}
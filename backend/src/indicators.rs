@@ -0,0 +1,257 @@
+//! Derives technical indicators from raw OHLC(V) data when a Parquet source doesn't already
+//! carry a precomputed column for them.
+
+/// Identifies a requested indicator and the parameters needed to compute it from raw OHLC(V).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndicatorSpec {
+    Ema { period: usize },
+    RsiWilder { period: usize },
+    RsiEma { period: usize },
+    AtrWilder { period: usize },
+    Vwap,
+}
+
+impl IndicatorSpec {
+    /// Parses an indicator id such as `ema_9`, `rsi_14_wilder`, `rsi_14_ema`, `atr_14`, or
+    /// `vwap` into the recurrence needed to compute it.
+    pub fn parse(id: &str) -> Result<Self, String> {
+        if id == "vwap" {
+            return Ok(IndicatorSpec::Vwap);
+        }
+
+        if let Some(period) = id.strip_prefix("ema_").and_then(|rest| rest.parse().ok()) {
+            return Ok(IndicatorSpec::Ema { period });
+        }
+
+        if let Some(period) = id
+            .strip_prefix("rsi_")
+            .and_then(|rest| rest.strip_suffix("_wilder"))
+            .and_then(|rest| rest.parse().ok())
+        {
+            return Ok(IndicatorSpec::RsiWilder { period });
+        }
+
+        if let Some(period) = id
+            .strip_prefix("rsi_")
+            .and_then(|rest| rest.strip_suffix("_ema"))
+            .and_then(|rest| rest.parse().ok())
+        {
+            return Ok(IndicatorSpec::RsiEma { period });
+        }
+
+        if let Some(period) = id.strip_prefix("atr_").and_then(|rest| rest.parse().ok()) {
+            return Ok(IndicatorSpec::AtrWilder { period });
+        }
+
+        Err(format!("Unknown indicator '{id}'"))
+    }
+
+    /// Indicates whether computing this indicator requires a `volume` column.
+    pub fn requires_volume(self) -> bool {
+        matches!(self, IndicatorSpec::Vwap)
+    }
+
+    /// Indicates overlay chart (`price`) or pane chart (for example `rsi`) placement.
+    pub fn pane(self) -> &'static str {
+        match self {
+            IndicatorSpec::RsiWilder { .. } | IndicatorSpec::RsiEma { .. } => "rsi",
+            IndicatorSpec::AtrWilder { .. } => "atr",
+            IndicatorSpec::Ema { .. } | IndicatorSpec::Vwap => "price",
+        }
+    }
+}
+
+/// Computes an exponential moving average over `closes`, seeded with the simple average of the
+/// first `period` closes. Points before the seed are `NaN` to mark the warm-up region.
+pub fn ema(closes: &[f64], period: usize) -> Vec<f64> {
+    let len = closes.len();
+    let mut output = vec![f64::NAN; len];
+
+    if period == 0 || len < period {
+        return output;
+    }
+
+    let alpha = 2.0 / (period as f64 + 1.0);
+    let seed = closes[..period].iter().sum::<f64>() / period as f64;
+    output[period - 1] = seed;
+
+    let mut previous = seed;
+    for (value, output_slot) in closes.iter().zip(output.iter_mut()).skip(period) {
+        let current = alpha * value + (1.0 - alpha) * previous;
+        *output_slot = current;
+        previous = current;
+    }
+
+    output
+}
+
+/// Computes Wilder-smoothed RSI over `closes`. Points before the seed are `NaN`.
+pub fn rsi_wilder(closes: &[f64], period: usize) -> Vec<f64> {
+    rsi(closes, period, Smoothing::Wilder)
+}
+
+/// Computes EMA-smoothed RSI over `closes`. Points before the seed are `NaN`.
+pub fn rsi_ema(closes: &[f64], period: usize) -> Vec<f64> {
+    rsi(closes, period, Smoothing::Ema)
+}
+
+enum Smoothing {
+    Wilder,
+    Ema,
+}
+
+fn rsi(closes: &[f64], period: usize, smoothing: Smoothing) -> Vec<f64> {
+    let len = closes.len();
+    let mut output = vec![f64::NAN; len];
+
+    if period == 0 || len <= period {
+        return output;
+    }
+
+    let mut gains = vec![0.0; len];
+    let mut losses = vec![0.0; len];
+
+    for i in 1..len {
+        let delta = closes[i] - closes[i - 1];
+        gains[i] = delta.max(0.0);
+        losses[i] = (-delta).max(0.0);
+    }
+
+    let mut avg_gain = gains[1..=period].iter().sum::<f64>() / period as f64;
+    let mut avg_loss = losses[1..=period].iter().sum::<f64>() / period as f64;
+    output[period] = rsi_from_averages(avg_gain, avg_loss);
+
+    let alpha = 2.0 / (period as f64 + 1.0);
+
+    for i in (period + 1)..len {
+        match smoothing {
+            Smoothing::Wilder => {
+                avg_gain = (avg_gain * (period as f64 - 1.0) + gains[i]) / period as f64;
+                avg_loss = (avg_loss * (period as f64 - 1.0) + losses[i]) / period as f64;
+            }
+            Smoothing::Ema => {
+                avg_gain = alpha * gains[i] + (1.0 - alpha) * avg_gain;
+                avg_loss = alpha * losses[i] + (1.0 - alpha) * avg_loss;
+            }
+        }
+
+        output[i] = rsi_from_averages(avg_gain, avg_loss);
+    }
+
+    output
+}
+
+fn rsi_from_averages(avg_gain: f64, avg_loss: f64) -> f64 {
+    if avg_loss == 0.0 {
+        100.0
+    } else {
+        100.0 - 100.0 / (1.0 + avg_gain / avg_loss)
+    }
+}
+
+/// Computes Wilder-smoothed Average True Range over `highs`/`lows`/`closes`. Points before the
+/// seed are `NaN`.
+pub fn atr_wilder(highs: &[f64], lows: &[f64], closes: &[f64], period: usize) -> Vec<f64> {
+    let len = highs.len();
+    let mut output = vec![f64::NAN; len];
+
+    if period == 0 || len <= period {
+        return output;
+    }
+
+    let mut true_ranges = vec![0.0; len];
+    true_ranges[0] = highs[0] - lows[0];
+
+    for i in 1..len {
+        let high_low = highs[i] - lows[i];
+        let high_close = (highs[i] - closes[i - 1]).abs();
+        let low_close = (lows[i] - closes[i - 1]).abs();
+        true_ranges[i] = high_low.max(high_close).max(low_close);
+    }
+
+    let mut avg_tr = true_ranges[1..=period].iter().sum::<f64>() / period as f64;
+    output[period] = avg_tr;
+
+    for i in (period + 1)..len {
+        avg_tr = (avg_tr * (period as f64 - 1.0) + true_ranges[i]) / period as f64;
+        output[i] = avg_tr;
+    }
+
+    output
+}
+
+/// Computes cumulative VWAP over `highs`/`lows`/`closes`/`volumes` using the typical price
+/// `(high + low + close) / 3`.
+pub fn vwap(highs: &[f64], lows: &[f64], closes: &[f64], volumes: &[f64]) -> Vec<f64> {
+    let len = highs.len();
+    let mut output = vec![f64::NAN; len];
+
+    let mut cumulative_price_volume = 0.0;
+    let mut cumulative_volume = 0.0;
+
+    for i in 0..len {
+        let typical_price = (highs[i] + lows[i] + closes[i]) / 3.0;
+        cumulative_price_volume += typical_price * volumes[i];
+        cumulative_volume += volumes[i];
+
+        if cumulative_volume > 0.0 {
+            output[i] = cumulative_price_volume / cumulative_volume;
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_indicator_ids() {
+        assert_eq!(IndicatorSpec::parse("vwap"), Ok(IndicatorSpec::Vwap));
+        assert_eq!(IndicatorSpec::parse("ema_9"), Ok(IndicatorSpec::Ema { period: 9 }));
+        assert_eq!(
+            IndicatorSpec::parse("rsi_14_wilder"),
+            Ok(IndicatorSpec::RsiWilder { period: 14 })
+        );
+        assert_eq!(
+            IndicatorSpec::parse("rsi_14_ema"),
+            Ok(IndicatorSpec::RsiEma { period: 14 })
+        );
+        assert_eq!(IndicatorSpec::parse("atr_14"), Ok(IndicatorSpec::AtrWilder { period: 14 }));
+        assert!(IndicatorSpec::parse("vwapn").is_err());
+    }
+
+    #[test]
+    fn ema_seeds_with_simple_average() {
+        let closes = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let values = ema(&closes, 3);
+
+        assert!(values[0].is_nan());
+        assert!(values[1].is_nan());
+        assert_eq!(values[2], 2.0);
+        assert!(values[3] > values[2]);
+    }
+
+    #[test]
+    fn rsi_wilder_is_100_when_no_losses() {
+        let closes = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let values = rsi_wilder(&closes, 3);
+
+        assert!(values[3].is_finite());
+        assert_eq!(values[3], 100.0);
+    }
+
+    #[test]
+    fn vwap_matches_cumulative_definition() {
+        let highs = [2.0, 2.0];
+        let lows = [1.0, 1.0];
+        let closes = [1.5, 1.5];
+        let volumes = [10.0, 10.0];
+
+        let values = vwap(&highs, &lows, &closes, &volumes);
+
+        assert_eq!(values[0], 1.5);
+        assert_eq!(values[1], 1.5);
+    }
+}
@@ -1,3 +1,6 @@
+mod filter;
+mod indicators;
+mod metrics;
 mod models;
 mod routes;
 
@@ -0,0 +1,102 @@
+//! Prometheus instrumentation for the backend. Registers a handful of counters and histograms
+//! covering HTTP traffic and Parquet access, and exposes them in text format at `/metrics`.
+
+use std::sync::LazyLock;
+use std::time::Instant;
+
+use axum::{body::Body, extract::MatchedPath, http::Request, middleware::Next, response::Response};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+
+static REGISTRY: LazyLock<Registry> = LazyLock::new(Registry::new);
+
+static HTTP_REQUESTS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let counter = IntCounterVec::new(
+        Opts::new("http_requests_total", "Total HTTP requests handled, by route and status code."),
+        &["route", "method", "status"],
+    )
+    .expect("valid metric definition");
+    REGISTRY.register(Box::new(counter.clone())).expect("metric registration");
+    counter
+});
+
+static HTTP_REQUEST_DURATION_SECONDS: LazyLock<HistogramVec> = LazyLock::new(|| {
+    let histogram = HistogramVec::new(
+        HistogramOpts::new("http_request_duration_seconds", "HTTP request duration in seconds, by route."),
+        &["route", "method"],
+    )
+    .expect("valid metric definition");
+    REGISTRY.register(Box::new(histogram.clone())).expect("metric registration");
+    histogram
+});
+
+static PARQUET_LOADS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let counter = IntCounterVec::new(
+        Opts::new("parquet_loads_total", "Parquet source open attempts, by outcome."),
+        &["outcome"],
+    )
+    .expect("valid metric definition");
+    REGISTRY.register(Box::new(counter.clone())).expect("metric registration");
+    counter
+});
+
+static POLARS_COLLECT_DURATION_SECONDS: LazyLock<HistogramVec> = LazyLock::new(|| {
+    let histogram = HistogramVec::new(
+        HistogramOpts::new(
+            "polars_collect_duration_seconds",
+            "Time spent in LazyFrame::collect(), by query, separate from HTTP overhead.",
+        ),
+        &["query"],
+    )
+    .expect("valid metric definition");
+    REGISTRY.register(Box::new(histogram.clone())).expect("metric registration");
+    histogram
+});
+
+/// Records whether a Parquet source was opened successfully or not.
+pub fn record_parquet_load(success: bool) {
+    let outcome = if success { "success" } else { "failure" };
+    PARQUET_LOADS_TOTAL.with_label_values(&[outcome]).inc();
+}
+
+/// Records how long a named `collect()` call took, in seconds.
+pub fn observe_collect_duration(query: &str, seconds: f64) {
+    POLARS_COLLECT_DURATION_SECONDS.with_label_values(&[query]).observe(seconds);
+}
+
+/// Axum middleware that records request counts and durations for every route. Must be installed
+/// with `Router::route_layer` rather than `Router::layer`: `route_layer` only runs for requests
+/// that matched a route, and only then has axum inserted `MatchedPath` into the request
+/// extensions, which keeps the `route` label bounded to the routes we actually defined instead of
+/// growing one series per unmatched path.
+pub async fn track_http_metrics(request: Request<Body>, next: Next) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+
+    let start = Instant::now();
+    let response = next.run(request).await;
+    let elapsed = start.elapsed().as_secs_f64();
+
+    HTTP_REQUEST_DURATION_SECONDS.with_label_values(&[&route, &method]).observe(elapsed);
+    HTTP_REQUESTS_TOTAL
+        .with_label_values(&[&route, &method, response.status().as_str()])
+        .inc();
+
+    response
+}
+
+/// Renders every registered metric in Prometheus text exposition format.
+pub fn render() -> String {
+    let metric_families = REGISTRY.gather();
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(&metric_families, &mut buffer)
+        .expect("metrics encode to valid utf8");
+
+    String::from_utf8(buffer).expect("prometheus text encoding is valid utf8")
+}
@@ -27,6 +27,8 @@ pub struct Candle {
 pub struct BarsResponse {
     /// Ordered candles in ascending timestamp order.
     pub candles: Vec<Candle>,
+    /// Opaque cursor to request the next page, present only when further candles remain.
+    pub next_cursor: Option<String>,
 }
 
 /// Represents one timestamped numeric point for a technical indicator series.
@@ -58,6 +60,8 @@ pub struct IndicatorSeries {
 pub struct SeriesResponse {
     /// The set of indicator series available for the requested dataset.
     pub series: Vec<IndicatorSeries>,
+    /// Opaque cursor to request the next page, present only when further points remain.
+    pub next_cursor: Option<String>,
 }
 
 /// Represents query parameters accepted by the bars endpoint.
@@ -69,11 +73,64 @@ pub struct BarsQuery {
     pub start: Option<String>,
     /// Inclusive upper date bound using YYYY-MM-DD format.
     pub end: Option<String>,
+    /// Resampling bucket size, one of `1m`, `5m`, `15m`, `1h`, `1d`. Defaults to the base resolution.
+    pub interval: Option<String>,
+    /// Maximum number of rows to return. Omitting it preserves the unpaginated response.
+    pub limit: Option<usize>,
+    /// Opaque pagination cursor returned as `next_cursor` by a previous page.
+    pub cursor: Option<String>,
+    /// Filter expression DSL string, e.g. `rsi_14_wilder < 30 AND close > 25.5`.
+    pub filter: Option<String>,
+    /// Comma-separated indicator ids to return, e.g. `ema_9,rsi_14_wilder,atr_14,vwap`. Defaults
+    /// to the historical precomputed-column set when omitted.
+    pub indicators: Option<String>,
 }
 
-/// Represents a structured API error payload used for bad requests.
+/// Represents the date coverage and row count available for one contract in the configured
+/// Parquet source.
+#[derive(Clone, Debug, Serialize)]
+pub struct ContractInfo {
+    /// Contract or symbol identifier.
+    pub contract: String,
+    /// Earliest bar timestamp for this contract, represented as UTC epoch seconds.
+    pub start: i64,
+    /// Latest bar timestamp for this contract, represented as UTC epoch seconds.
+    pub end: i64,
+    /// Number of rows available for this contract.
+    pub row_count: i64,
+}
+
+/// Wraps the set of contracts discoverable in the configured Parquet source.
 #[derive(Debug, Serialize)]
-pub struct ApiErrorResponse {
-    /// Short, user-readable description of the request error.
+pub struct ContractsResponse {
+    /// Every distinct contract present in the data, sorted by identifier.
+    pub contracts: Vec<ContractInfo>,
+}
+
+/// Represents a single, machine-readable validation or query failure.
+#[derive(Clone, Debug, Serialize)]
+pub struct ApiError {
+    /// Stable, programmatically-branchable identifier, e.g. `invalid_date` or `unknown_contract`.
+    pub code: String,
+    /// Human-readable description of the error suitable for direct display.
     pub message: String,
 }
+
+impl ApiError {
+    /// Constructs an error with the given stable `code` and human-readable `message`.
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Represents a structured API error payload used for bad requests. Carries every validation
+/// failure found for a request so the caller can surface them all at once instead of fixing one
+/// field at a time.
+#[derive(Debug, Serialize)]
+pub struct ApiErrorResponse {
+    /// Every error found while validating or executing the request.
+    pub errors: Vec<ApiError>,
+}
@@ -7,7 +7,10 @@ use axum::{
 
 use crate::{
     data::bars::BarsRepository,
-    models::{ApiErrorResponse, BarsQuery, BarsResponse, HealthResponse, SeriesResponse},
+    metrics,
+    models::{
+        ApiError, ApiErrorResponse, BarsQuery, BarsResponse, ContractsResponse, HealthResponse, SeriesResponse,
+    },
 };
 
 /// Shared application state containing repositories used by route handlers.
@@ -34,6 +37,9 @@ pub fn app_router() -> Router {
         .route("/health", get(health))
         .route("/bars", get(bars))
         .route("/series", get(series))
+        .route("/contracts", get(contracts))
+        .route("/metrics", get(metrics_endpoint))
+        .route_layer(axum::middleware::from_fn(metrics::track_http_metrics))
         .with_state(state)
 }
 
@@ -42,21 +48,44 @@ async fn health() -> Json<HealthResponse> {
     Json(HealthResponse { status: "ok" })
 }
 
+/// Picks the overall HTTP status for a batch of `ApiError`s. Any error that reflects a problem on
+/// our end (an unreadable data source or a failed query) outranks client-side validation errors,
+/// since returning 500 is more honest than blaming the request.
+fn response_status(errors: &[ApiError]) -> StatusCode {
+    let is_server_error = |error: &ApiError| matches!(error.code.as_str(), "data_source_missing" | "query_failed");
+
+    if errors.iter().any(is_server_error) {
+        StatusCode::INTERNAL_SERVER_ERROR
+    } else {
+        StatusCode::BAD_REQUEST
+    }
+}
+
 /// Returns candlestick bars filtered by contract and date query parameters.
 async fn bars(
     State(state): State<AppState>,
     Query(query): Query<BarsQuery>,
 ) -> Result<Json<BarsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
-    let candles = state
+    let (candles, next_cursor) = state
         .bars_repository
         .load_bars(
             query.contract.as_deref(),
             query.start.as_deref(),
             query.end.as_deref(),
+            query.interval.as_deref(),
+            query.limit,
+            query.cursor.as_deref(),
+            query.filter.as_deref(),
         )
-        .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiErrorResponse { message })))?;
+        .map_err(|errors| {
+            let status = response_status(&errors);
+            (status, Json(ApiErrorResponse { errors }))
+        })?;
 
-    Ok(Json(BarsResponse { candles }))
+    Ok(Json(BarsResponse {
+        candles,
+        next_cursor,
+    }))
 }
 
 /// Returns indicator series filtered by contract and date query parameters.
@@ -64,14 +93,43 @@ async fn series(
     State(state): State<AppState>,
     Query(query): Query<BarsQuery>,
 ) -> Result<Json<SeriesResponse>, (StatusCode, Json<ApiErrorResponse>)> {
-    let series = state
+    let (series, next_cursor) = state
         .bars_repository
         .load_series(
             query.contract.as_deref(),
             query.start.as_deref(),
             query.end.as_deref(),
+            query.limit,
+            query.cursor.as_deref(),
+            query.filter.as_deref(),
+            query.indicators.as_deref(),
         )
-        .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiErrorResponse { message })))?;
+        .map_err(|errors| {
+            let status = response_status(&errors);
+            (status, Json(ApiErrorResponse { errors }))
+        })?;
+
+    Ok(Json(SeriesResponse {
+        series,
+        next_cursor,
+    }))
+}
+
+/// Returns every contract available in the configured Parquet source along with its date
+/// coverage, powering a symbol picker and date-range clamping in the UI.
+async fn contracts(
+    State(state): State<AppState>,
+) -> Result<Json<ContractsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let contracts = state.bars_repository.load_contracts().map_err(|error| {
+        let errors = vec![error];
+        let status = response_status(&errors);
+        (status, Json(ApiErrorResponse { errors }))
+    })?;
+
+    Ok(Json(ContractsResponse { contracts }))
+}
 
-    Ok(Json(SeriesResponse { series }))
+/// Returns every registered metric in Prometheus text exposition format.
+async fn metrics_endpoint() -> String {
+    metrics::render()
 }